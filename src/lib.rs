@@ -0,0 +1,199 @@
+/// Parity checking modes
+///
+/// When parity checking is enabled (odd, even, mark, or space) an extra bit is
+/// transmitted with each character. The value of that bit is arranged so that the
+/// number of 1 bits in the character (including the parity bit) is even (`Even`) or
+/// odd (`Odd`), or the bit is simply held at a fixed value (`Mark`/`Space`).
+///
+/// Parity checking is disabled by setting the parity to `None`, which is the
+/// default setting for all platforms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    /// No parity bit.
+    None,
+    /// Parity bit sets odd number of 1 bits.
+    Odd,
+    /// Parity bit sets even number of 1 bits.
+    Even,
+    /// Parity bit is always 1 (mark). Used by 9-bit/multidrop addressing protocols
+    /// to distinguish address bytes from data bytes.
+    Mark,
+    /// Parity bit is always 0 (space). Used by 9-bit/multidrop addressing protocols
+    /// to distinguish address bytes from data bytes.
+    Space,
+}
+
+/// Number of bits per character
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataBits {
+    /// 5 bits per character
+    Five,
+    /// 6 bits per character
+    Six,
+    /// 7 bits per character
+    Seven,
+    /// 8 bits per character
+    Eight,
+}
+
+/// Number of stop bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopBits {
+    /// One stop bit
+    One,
+    /// Two stop bits
+    Two,
+}
+
+/// Flow control modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowControl {
+    /// No flow control
+    None,
+    /// Flow control using XON/XOFF bytes
+    Software,
+    /// Flow control using RTS/CTS signals
+    Hardware,
+}
+
+/// Which buffer(s) to purge with [`SerialPort::clear`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClearBuffer {
+    /// Clear data received but not yet read
+    Input,
+    /// Clear data written but not yet transmitted
+    Output,
+    /// Clear both data received but not yet read, and written but not yet transmitted
+    All,
+}
+
+/// A type for results generated by this crate's functions, where the `Err` type is
+/// hard-wired to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Categories of errors that can occur when interacting with serial ports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The device is not available, e.g. because it is already in use
+    NoDevice,
+    /// An argument provided by the caller was invalid, e.g. an unparsable settings
+    /// string
+    InvalidInput,
+    /// The value of a setting read back from the device did not correspond to any
+    /// value this crate knows how to represent
+    Unknown,
+    /// An I/O error occurred while opening or using the device
+    Io(std::io::ErrorKind),
+}
+
+/// An error type for serial port operations
+#[derive(Debug, Clone)]
+pub struct Error {
+    kind: ErrorKind,
+    description: String,
+}
+
+impl Error {
+    /// Creates a new error with the given kind and description
+    pub fn new<T: Into<String>>(kind: ErrorKind, description: T) -> Self {
+        Error {
+            kind,
+            description: description.into(),
+        }
+    }
+
+    /// Returns the category of this error
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the description of this error
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl std::error::Error for Error {}
+
+use crate::sys::windows::com::{DtrControl, ReadTimeoutMode, RtsControl};
+use crate::sys::windows::dcb;
+
+/// A builder for the Windows [`SerialPort`][crate::sys::windows::com::SerialPort]'s settings
+///
+/// This is consumed by `SerialPort::open`, which applies every field to the
+/// underlying handle in one pass.
+#[derive(Debug, Clone)]
+pub struct SerialPortBuilder {
+    pub(crate) baud_rate: u32,
+    pub(crate) data_bits: DataBits,
+    pub(crate) parity: Parity,
+    pub(crate) stop_bits: StopBits,
+    pub(crate) flow_control: FlowControl,
+    pub(crate) dtr_control: DtrControl,
+    pub(crate) rts_control: RtsControl,
+    pub(crate) initial_dtr_level: bool,
+    pub(crate) initial_rts_level: bool,
+    pub(crate) read_timeout_mode: ReadTimeoutMode,
+    pub(crate) read_timeout: Option<std::time::Duration>,
+    pub(crate) write_timeout: Option<std::time::Duration>,
+    pub(crate) overlapped: bool,
+}
+
+impl SerialPortBuilder {
+    /// Creates a builder with this crate's long-standing defaults: 8 data bits, no
+    /// parity, one stop bit, no flow control, DTR/RTS disabled, synchronous I/O, and
+    /// the legacy read-timeout behavior.
+    pub fn new(baud_rate: u32) -> Self {
+        SerialPortBuilder {
+            baud_rate,
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+            flow_control: FlowControl::None,
+            dtr_control: DtrControl::Disable,
+            rts_control: RtsControl::Disable,
+            initial_dtr_level: false,
+            initial_rts_level: false,
+            read_timeout_mode: ReadTimeoutMode::Default,
+            read_timeout: None,
+            write_timeout: None,
+            overlapped: false,
+        }
+    }
+
+    /// Builds a [`SerialPortBuilder`] from a terse settings string such as
+    /// `"baud=1000000 data=8 parity=n stop=1"`, without needing an already-open
+    /// handle.
+    ///
+    /// This is the builder-side counterpart to
+    /// [`SerialPort::apply_settings_string`][crate::sys::windows::com::SerialPort::apply_settings_string]
+    /// — both are backed by the same `BuildCommDCBW` call, so they accept exactly
+    /// the same keywords (see `BuildCommDCB` on MSDN) and never drift apart. This is
+    /// for constructing a port from a config file or CLI argument before it is
+    /// opened; settings not representable in a DCB (e.g. `overlapped`) are left at
+    /// [`SerialPortBuilder::new`]'s defaults.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidInput` if `settings` is not accepted by `BuildCommDCBW`.
+    pub fn from_settings_string(settings: &str) -> Result<Self> {
+        let parsed = dcb::parse_settings_string(settings)?;
+
+        let mut builder = SerialPortBuilder::new(dcb::baud_rate(&parsed));
+        builder.data_bits = dcb::data_bits(&parsed)?;
+        builder.parity = dcb::parity(&parsed)?;
+        builder.stop_bits = dcb::stop_bits(&parsed)?;
+        builder.flow_control = dcb::flow_control(&parsed)?;
+        builder.dtr_control = dcb::dtr_control(&parsed)?;
+        builder.rts_control = dcb::rts_control(&parsed)?;
+
+        Ok(builder)
+    }
+}
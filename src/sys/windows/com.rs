@@ -7,10 +7,15 @@ use std::time::Duration;
 use std::{io, mem, ptr};
 
 use winapi::shared::minwindef::*;
+use winapi::shared::winerror::ERROR_IO_PENDING;
 use winapi::um::commapi::*;
+use winapi::um::errhandlingapi::GetLastError;
 use winapi::um::fileapi::*;
 use winapi::um::handleapi::*;
+use winapi::um::ioapiset::{CancelIoEx, GetOverlappedResult};
+use winapi::um::minwinbase::OVERLAPPED;
 use winapi::um::processthreadsapi::GetCurrentProcess;
+use winapi::um::synchapi::{CreateEventW, WaitForSingleObject};
 use winapi::um::winbase::*;
 use winapi::um::winnt::{
     DUPLICATE_SAME_ACCESS, FILE_ATTRIBUTE_NORMAL, GENERIC_READ, GENERIC_WRITE, HANDLE,
@@ -22,6 +27,167 @@ use crate::{
     StopBits,
 };
 
+/// An auto-reset event used to signal completion of a single overlapped
+/// I/O operation.
+#[derive(Debug)]
+struct Event(HANDLE);
+
+impl Event {
+    /// Creates a new auto-reset, initially non-signaled event.
+    fn new() -> Result<Self> {
+        let handle = unsafe { CreateEventW(ptr::null_mut(), FALSE, FALSE, ptr::null()) };
+        if handle.is_null() {
+            Err(super::error::last_os_error())
+        } else {
+            Ok(Event(handle))
+        }
+    }
+
+    fn handle(&self) -> HANDLE {
+        self.0
+    }
+}
+
+unsafe impl Send for Event {}
+
+impl Drop for Event {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+/// A set of line-status and data events to wait for with [`SerialPort::wait_for_event`].
+///
+/// These correspond to the `EV_*` flags accepted by `SetCommMask`/`WaitCommEvent`.
+/// Combine flags with `|`, e.g. `CommEvent::CTS_CHANGED | CommEvent::DSR_CHANGED`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommEvent(DWORD);
+
+impl CommEvent {
+    /// A character was received and placed in the input buffer.
+    pub const RX_CHAR: CommEvent = CommEvent(EV_RXCHAR);
+    /// The CTS (clear-to-send) signal changed state.
+    pub const CTS_CHANGED: CommEvent = CommEvent(EV_CTS);
+    /// The DSR (data-set-ready) signal changed state.
+    pub const DSR_CHANGED: CommEvent = CommEvent(EV_DSR);
+    /// The RI (ring indicator) signal changed state.
+    pub const RING_CHANGED: CommEvent = CommEvent(EV_RING);
+    /// The RLSD (carrier detect) signal changed state.
+    pub const RLSD_CHANGED: CommEvent = CommEvent(EV_RLSD);
+    /// A break was detected on the input line.
+    pub const BREAK_DETECT: CommEvent = CommEvent(EV_BREAK);
+    /// A line-status error (framing, parity, or overrun) occurred.
+    pub const ERROR: CommEvent = CommEvent(EV_ERR);
+
+    /// Returns `true` if `self` includes `other`.
+    pub fn contains(self, other: CommEvent) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn bits(self) -> DWORD {
+        self.0
+    }
+}
+
+impl std::ops::BitOr for CommEvent {
+    type Output = CommEvent;
+
+    fn bitor(self, rhs: CommEvent) -> CommEvent {
+        CommEvent(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for CommEvent {
+    fn bitor_assign(&mut self, rhs: CommEvent) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Line-quality error flags reported by `ClearCommError`, as returned by
+/// [`SerialPort::check_comm_errors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommErrors(DWORD);
+
+impl CommErrors {
+    /// The hardware detected a framing error.
+    pub const FRAME: CommErrors = CommErrors(CE_FRAME);
+    /// A character-buffer overrun occurred; a character was not read from the
+    /// hardware before the next character arrived.
+    pub const OVERRUN: CommErrors = CommErrors(CE_OVERRUN);
+    /// The hardware detected a parity error.
+    pub const RX_PARITY: CommErrors = CommErrors(CE_RXPARITY);
+    /// A break was detected on input.
+    pub const BREAK: CommErrors = CommErrors(CE_BREAK);
+    /// An input buffer overflow occurred; there was no room in the input buffer.
+    pub const RX_OVER: CommErrors = CommErrors(CE_RXOVER);
+    /// The application tried to write to a full output buffer.
+    pub const TX_FULL: CommErrors = CommErrors(CE_TXFULL);
+
+    /// Returns `true` if `self` includes `other`.
+    pub fn contains(self, other: CommErrors) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for CommErrors {
+    type Output = CommErrors;
+
+    fn bitor(self, rhs: CommErrors) -> CommErrors {
+        CommErrors(self.0 | rhs.0)
+    }
+}
+
+/// The DTR (data-terminal-ready) line control mode, as stored in the DCB's
+/// `fDtrControl` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DtrControl {
+    /// DTR is disabled when the device is opened and left disabled.
+    Disable,
+    /// DTR is enabled when the device is opened and left enabled.
+    Enable,
+    /// DTR handshaking is used: the driver asserts/deasserts DTR based on the
+    /// state of the input buffer, so it should not be toggled manually.
+    Handshake,
+}
+
+/// The RTS (request-to-send) line control mode, as stored in the DCB's
+/// `fRtsControl` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtsControl {
+    /// RTS is disabled when the device is opened and left disabled.
+    Disable,
+    /// RTS is enabled when the device is opened and left enabled.
+    Enable,
+    /// RTS handshaking is used: the driver controls RTS based on whether the
+    /// input buffer has room, so it should not be toggled manually.
+    Handshake,
+    /// RTS toggles depending on whether there is data to send: it is enabled
+    /// if there is data available to send and disabled otherwise.
+    Toggle,
+}
+
+/// Selects how the read-side fields of `COMMTIMEOUTS` are configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadTimeoutMode {
+    /// Wait up to the configured read timeout for the first byte, with a 1 ms
+    /// allowance between subsequent bytes. This is the long-standing default
+    /// behavior of this crate.
+    Default,
+    /// Return immediately with whatever bytes are already in the input buffer. When
+    /// the buffer is empty, a synchronous `read` reports this as a `TimedOut` error
+    /// and an overlapped `read` returns `Ok(0)`. Useful for polling-style readers.
+    NonBlocking,
+    /// Wait up to the configured read timeout for the whole read, ignoring gaps
+    /// between bytes.
+    Total,
+    /// Return once more than the configured read timeout elapses between two
+    /// consecutive bytes. Useful for protocol parsers that frame messages on
+    /// inter-character gaps.
+    InterByteGap,
+}
+
 /// A serial port implementation for Windows COM ports
 ///
 /// The port will be closed when the value is dropped. However, this struct
@@ -33,7 +199,11 @@ pub struct SerialPort {
     handle: HANDLE,
     read_timeout: Option<Duration>,
     write_timeout: Option<Duration>,
+    read_timeout_mode: ReadTimeoutMode,
     port_name: Option<String>,
+    overlapped: bool,
+    read_event: Option<Event>,
+    write_event: Option<Event>,
 }
 
 unsafe impl Send for SerialPort {}
@@ -62,6 +232,12 @@ impl SerialPort {
             .chain(std::iter::once(0))
             .collect();
 
+        let flags_and_attributes = if builder.overlapped {
+            FILE_ATTRIBUTE_NORMAL | FILE_FLAG_OVERLAPPED
+        } else {
+            FILE_ATTRIBUTE_NORMAL
+        };
+
         let handle = unsafe {
             CreateFileW(
                 name.as_ptr(),
@@ -69,7 +245,7 @@ impl SerialPort {
                 0,
                 ptr::null_mut(),
                 OPEN_EXISTING,
-                FILE_ATTRIBUTE_NORMAL,
+                flags_and_attributes,
                 0 as HANDLE,
             )
         };
@@ -84,10 +260,37 @@ impl SerialPort {
         dcb::set_data_bits(&mut dcb, builder.data_bits);
         dcb::set_parity(&mut dcb, builder.parity);
         dcb::set_stop_bits(&mut dcb, builder.stop_bits);
+        // dtr_control/rts_control are applied before set_flow_control so that
+        // rts_control gets to own fRtsControl; set_flow_control only overrides it
+        // back for FlowControl::Hardware, which must force RTS_CONTROL_HANDSHAKE.
+        dcb::set_dtr_control(&mut dcb, builder.dtr_control);
+        dcb::set_rts_control(&mut dcb, builder.rts_control);
         dcb::set_flow_control(&mut dcb, builder.flow_control);
         dcb::set_dcb(handle, dcb)?;
 
         let mut com = SerialPort::open_from_raw_handle(handle as RawHandle);
+        com.overlapped = builder.overlapped;
+        if builder.overlapped {
+            com.read_event = Some(Event::new()?);
+            com.write_event = Some(Event::new()?);
+        }
+        // Apply the initial pin levels immediately, before returning the port to the
+        // caller, so there is no window where DTR/RTS sit in a driver-chosen state.
+        // Only meaningful for the two modes where the line is under manual control;
+        // pulsing it via EscapeCommFunction while Handshake/Toggle owns the line
+        // contradicts that mode and can fail. For RTS, FlowControl::Hardware also
+        // claims the line (forcing RTS_CONTROL_HANDSHAKE regardless of rts_control),
+        // so it must be excluded from the pulse too.
+        if builder.dtr_control == DtrControl::Enable || builder.dtr_control == DtrControl::Disable {
+            com.write_data_terminal_ready(builder.initial_dtr_level)?;
+        }
+        if builder.flow_control != FlowControl::Hardware
+            && (builder.rts_control == RtsControl::Enable
+                || builder.rts_control == RtsControl::Disable)
+        {
+            com.write_request_to_send(builder.initial_rts_level)?;
+        }
+        com.read_timeout_mode = builder.read_timeout_mode;
         com.set_timeouts(builder.read_timeout, builder.write_timeout)?;
         com.port_name = Some(path.to_string_lossy().into_owned());
         Ok(com)
@@ -119,11 +322,23 @@ impl SerialPort {
                 DUPLICATE_SAME_ACCESS,
             );
             if cloned_handle != INVALID_HANDLE_VALUE {
+                // Events are tied to a single overlapped operation in flight on a single
+                // handle, so a clone needs its own pair rather than sharing ours.
+                let (read_event, write_event) = if self.overlapped {
+                    (Some(Event::new()?), Some(Event::new()?))
+                } else {
+                    (None, None)
+                };
+
                 Ok(SerialPort {
                     handle: cloned_handle,
                     port_name: self.port_name.clone(),
                     read_timeout: self.read_timeout,
                     write_timeout: self.write_timeout,
+                    read_timeout_mode: self.read_timeout_mode,
+                    overlapped: self.overlapped,
+                    read_event,
+                    write_event,
                 })
             } else {
                 Err(super::error::last_os_error())
@@ -155,9 +370,15 @@ impl SerialPort {
             // Instead we just set `None` and add a warning to `FromRawHandle`.
             read_timeout: None,
             write_timeout: None,
+            read_timeout_mode: ReadTimeoutMode::Default,
             // It is not trivial to get the file path corresponding to a handle.
             // We'll punt and set it `None` here.
             port_name: None,
+            // We have no way of knowing whether the handle was opened with
+            // `FILE_FLAG_OVERLAPPED`, so assume the common synchronous case.
+            overlapped: false,
+            read_event: None,
+            write_event: None,
         }
     }
 
@@ -181,6 +402,15 @@ impl SerialPort {
         self.set_timeouts(self.read_timeout, write_timeout)
     }
 
+    pub fn read_timeout_mode(&self) -> ReadTimeoutMode {
+        self.read_timeout_mode
+    }
+
+    pub fn set_read_timeout_mode(&mut self, read_timeout_mode: ReadTimeoutMode) -> Result<()> {
+        self.read_timeout_mode = read_timeout_mode;
+        self.set_timeouts(self.read_timeout, self.write_timeout)
+    }
+
     fn set_timeouts(
         &mut self,
         read_timeout: Option<Duration>,
@@ -200,10 +430,17 @@ impl SerialPort {
             None => 0,
         };
 
+        let (read_interval_timeout, read_total_timeout_constant) = match self.read_timeout_mode {
+            ReadTimeoutMode::Default => (1, read_timeout_ms),
+            ReadTimeoutMode::NonBlocking => (MAXDWORD, 0),
+            ReadTimeoutMode::Total => (0, read_timeout_ms),
+            ReadTimeoutMode::InterByteGap => (read_timeout_ms, 0),
+        };
+
         let mut timeouts = COMMTIMEOUTS {
-            ReadIntervalTimeout: 1,
+            ReadIntervalTimeout: read_interval_timeout,
             ReadTotalTimeoutMultiplier: 0,
-            ReadTotalTimeoutConstant: read_timeout_ms,
+            ReadTotalTimeoutConstant: read_total_timeout_constant,
             WriteTotalTimeoutMultiplier: 0,
             WriteTotalTimeoutConstant: write_timeout_ms,
         };
@@ -251,57 +488,37 @@ impl SerialPort {
 
     pub fn baud_rate(&self) -> Result<u32> {
         let dcb = dcb::get_dcb(self.handle)?;
-        Ok(dcb.BaudRate as u32)
+        Ok(dcb::baud_rate(&dcb))
     }
 
     pub fn data_bits(&self) -> Result<DataBits> {
         let dcb = dcb::get_dcb(self.handle)?;
-        match dcb.ByteSize {
-            5 => Ok(DataBits::Five),
-            6 => Ok(DataBits::Six),
-            7 => Ok(DataBits::Seven),
-            8 => Ok(DataBits::Eight),
-            _ => Err(Error::new(
-                ErrorKind::Unknown,
-                "Invalid data bits setting encountered",
-            )),
-        }
+        dcb::data_bits(&dcb)
     }
 
     pub fn parity(&self) -> Result<Parity> {
         let dcb = dcb::get_dcb(self.handle)?;
-        match dcb.Parity {
-            ODDPARITY => Ok(Parity::Odd),
-            EVENPARITY => Ok(Parity::Even),
-            NOPARITY => Ok(Parity::None),
-            _ => Err(Error::new(
-                ErrorKind::Unknown,
-                "Invalid parity bits setting encountered",
-            )),
-        }
+        dcb::parity(&dcb)
     }
 
     pub fn stop_bits(&self) -> Result<StopBits> {
         let dcb = dcb::get_dcb(self.handle)?;
-        match dcb.StopBits {
-            TWOSTOPBITS => Ok(StopBits::Two),
-            ONESTOPBIT => Ok(StopBits::One),
-            _ => Err(Error::new(
-                ErrorKind::Unknown,
-                "Invalid stop bits setting encountered",
-            )),
-        }
+        dcb::stop_bits(&dcb)
     }
 
     pub fn flow_control(&self) -> Result<FlowControl> {
         let dcb = dcb::get_dcb(self.handle)?;
-        if dcb.fOutxCtsFlow() != 0 || dcb.fRtsControl() != 0 {
-            Ok(FlowControl::Hardware)
-        } else if dcb.fOutX() != 0 || dcb.fInX() != 0 {
-            Ok(FlowControl::Software)
-        } else {
-            Ok(FlowControl::None)
-        }
+        dcb::flow_control(&dcb)
+    }
+
+    pub fn dtr_control(&self) -> Result<DtrControl> {
+        let dcb = dcb::get_dcb(self.handle)?;
+        dcb::dtr_control(&dcb)
+    }
+
+    pub fn rts_control(&self) -> Result<RtsControl> {
+        let dcb = dcb::get_dcb(self.handle)?;
+        dcb::rts_control(&dcb)
     }
 
     pub fn set_baud_rate(&mut self, baud_rate: u32) -> Result<()> {
@@ -334,28 +551,63 @@ impl SerialPort {
         dcb::set_dcb(self.handle, dcb)
     }
 
-    pub fn bytes_to_read(&self) -> Result<u32> {
-        let mut errors: DWORD = 0;
-        let mut comstat = MaybeUninit::uninit();
+    /// Applies a terse mode string, e.g. `"baud=1000000 data=8 parity=n stop=1"`, to
+    /// the already-open port via `BuildCommDCBW`.
+    ///
+    /// This is a convenient single-call alternative to a series of `set_*` calls,
+    /// handy for config files and CLI arguments. See `BuildCommDCB` on MSDN for the
+    /// accepted keywords.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidInput` if `settings` could not be parsed by `BuildCommDCBW`.
+    pub fn apply_settings_string(&mut self, settings: &str) -> Result<()> {
+        let mut dcb = dcb::get_dcb(self.handle)?;
 
-        if unsafe { ClearCommError(self.handle, &mut errors, comstat.as_mut_ptr()) != 0 } {
-            unsafe { Ok(comstat.assume_init().cbInQue) }
-        } else {
-            Err(super::error::last_os_error())
+        let settings: Vec<u16> = OsStr::new(settings)
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        if unsafe { BuildCommDCBW(settings.as_ptr(), &mut dcb) } == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Could not parse settings string",
+            ));
         }
+
+        dcb::set_dcb(self.handle, dcb)
     }
 
-    pub fn bytes_to_write(&self) -> Result<u32> {
+    /// Calls `ClearCommError` and returns both the accumulated error flags and the
+    /// `COMSTAT` queue-depth counters, so callers needing either can share one syscall.
+    fn clear_comm_error(&self) -> Result<(DWORD, COMSTAT)> {
         let mut errors: DWORD = 0;
         let mut comstat = MaybeUninit::uninit();
 
         if unsafe { ClearCommError(self.handle, &mut errors, comstat.as_mut_ptr()) != 0 } {
-            unsafe { Ok(comstat.assume_init().cbOutQue) }
+            unsafe { Ok((errors, comstat.assume_init())) }
         } else {
             Err(super::error::last_os_error())
         }
     }
 
+    pub fn bytes_to_read(&self) -> Result<u32> {
+        self.clear_comm_error().map(|(_, comstat)| comstat.cbInQue)
+    }
+
+    pub fn bytes_to_write(&self) -> Result<u32> {
+        self.clear_comm_error().map(|(_, comstat)| comstat.cbOutQue)
+    }
+
+    /// Returns the framing/parity/overrun/break errors accumulated since the last
+    /// call, as reported by `ClearCommError`. Calling this (or `bytes_to_read`/
+    /// `bytes_to_write`, which share the same underlying call) clears the flags.
+    pub fn check_comm_errors(&self) -> Result<CommErrors> {
+        self.clear_comm_error()
+            .map(|(errors, _)| CommErrors(errors))
+    }
+
     pub fn clear(&self, buffer_to_clear: ClearBuffer) -> Result<()> {
         let buffer_flags = match buffer_to_clear {
             ClearBuffer::Input => PURGE_RXABORT | PURGE_RXCLEAR,
@@ -385,6 +637,209 @@ impl SerialPort {
             Err(super::error::last_os_error())
         }
     }
+
+    /// Sets the events `wait_for_event` will wake up for.
+    ///
+    /// This must be called before `wait_for_event`; it has no default mask.
+    pub fn set_comm_mask(&mut self, mask: CommEvent) -> Result<()> {
+        if unsafe { SetCommMask(self.handle, mask.bits()) } != 0 {
+            Ok(())
+        } else {
+            Err(super::error::last_os_error())
+        }
+    }
+
+    /// Blocks until one of the events set by `set_comm_mask` occurs, or `timeout` elapses.
+    ///
+    /// Returns the set of events that actually occurred. Because `WaitCommEvent` blocks
+    /// indefinitely and cannot be cancelled cleanly in synchronous mode, this is
+    /// implemented through an overlapped wait on its own event handle, which only
+    /// works on a handle opened with `FILE_FLAG_OVERLAPPED`. The port must therefore
+    /// have been opened in overlapped mode.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidInput` if the port was not opened in overlapped mode.
+    /// * `TimedOut` if `timeout` elapses before an event occurs.
+    pub fn wait_for_event(&self, timeout: Option<Duration>) -> Result<CommEvent> {
+        if !self.overlapped {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "wait_for_event requires the port to be opened in overlapped mode",
+            ));
+        }
+
+        let event = Event::new()?;
+        let mut overlapped: OVERLAPPED = unsafe { mem::zeroed() };
+        overlapped.hEvent = event.handle();
+
+        let mut mask: DWORD = 0;
+        if unsafe { WaitCommEvent(self.handle, &mut mask, &mut overlapped) } != 0 {
+            return Ok(CommEvent(mask));
+        }
+
+        if unsafe { GetLastError() } != ERROR_IO_PENDING {
+            return Err(super::error::last_os_error());
+        }
+
+        let timeout_ms = match timeout {
+            // DWORD::MAX is INFINITE, so an overlong-but-finite timeout must not be
+            // allowed to round up to it; clamp to the largest non-infinite value.
+            Some(duration) => DWORD::try_from(duration.as_millis())
+                .unwrap_or(DWORD::MAX - 1)
+                .clamp(1, DWORD::MAX - 1),
+            None => INFINITE,
+        };
+
+        match unsafe { WaitForSingleObject(event.handle(), timeout_ms) } {
+            WAIT_OBJECT_0 => {
+                let mut len: DWORD = 0;
+                if unsafe { GetOverlappedResult(self.handle, &mut overlapped, &mut len, FALSE) }
+                    == 0
+                {
+                    Err(super::error::last_os_error())
+                } else {
+                    Ok(CommEvent(mask))
+                }
+            }
+            WAIT_TIMEOUT => {
+                unsafe { CancelIoEx(self.handle, &mut overlapped) };
+                // As in `wait_overlapped`: CancelIoEx only requests cancellation, so we
+                // must wait for it to finalize before `overlapped`/`mask` go out of scope.
+                let mut len: DWORD = 0;
+                unsafe { GetOverlappedResult(self.handle, &mut overlapped, &mut len, TRUE) };
+                Err(Error::new(
+                    ErrorKind::Io(io::ErrorKind::TimedOut),
+                    "Operation timed out",
+                ))
+            }
+            _ => Err(super::error::last_os_error()),
+        }
+    }
+
+    /// Returns the event handle signaled on completion of a pending overlapped read,
+    /// or `None` if overlapped mode is not enabled.
+    ///
+    /// This lets an external reactor (e.g. a `mio`/`tokio` integration) wait on the
+    /// same event this `SerialPort` uses internally instead of polling.
+    pub fn read_event_handle(&self) -> Option<RawHandle> {
+        self.read_event
+            .as_ref()
+            .map(|event| event.handle() as RawHandle)
+    }
+
+    /// Returns the event handle signaled on completion of a pending overlapped write,
+    /// or `None` if overlapped mode is not enabled.
+    pub fn write_event_handle(&self) -> Option<RawHandle> {
+        self.write_event
+            .as_ref()
+            .map(|event| event.handle() as RawHandle)
+    }
+
+    /// Waits on `event` for the completion of the overlapped operation described by
+    /// `overlapped`, honoring `timeout`. On timeout, the operation is cancelled.
+    fn wait_overlapped(
+        &self,
+        overlapped: &mut OVERLAPPED,
+        event: HANDLE,
+        timeout: Option<Duration>,
+    ) -> io::Result<usize> {
+        let timeout_ms = match timeout {
+            // DWORD::MAX is INFINITE, so an overlong-but-finite timeout must not be
+            // allowed to round up to it; clamp to the largest non-infinite value.
+            Some(duration) => DWORD::try_from(duration.as_millis())
+                .unwrap_or(DWORD::MAX - 1)
+                .clamp(1, DWORD::MAX - 1),
+            None => INFINITE,
+        };
+
+        match unsafe { WaitForSingleObject(event, timeout_ms) } {
+            WAIT_OBJECT_0 => {
+                let mut len: DWORD = 0;
+                if unsafe { GetOverlappedResult(self.handle, overlapped, &mut len, FALSE) } == 0 {
+                    Err(io::Error::last_os_error())
+                } else {
+                    Ok(len as usize)
+                }
+            }
+            WAIT_TIMEOUT => {
+                unsafe { CancelIoEx(self.handle, overlapped) };
+                // CancelIoEx only requests cancellation; the kernel may still be
+                // writing into `overlapped`/the caller's buffer when it returns. Block
+                // until the cancellation is actually finalized before letting those go
+                // out of scope, otherwise this is a use-after-free.
+                let mut len: DWORD = 0;
+                unsafe { GetOverlappedResult(self.handle, overlapped, &mut len, TRUE) };
+                Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "Operation timed out",
+                ))
+            }
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+
+    fn read_overlapped(&self, buf: &mut [u8]) -> io::Result<usize> {
+        let event = self
+            .read_event
+            .as_ref()
+            .expect("overlapped mode enabled without a read event");
+        let mut overlapped: OVERLAPPED = unsafe { mem::zeroed() };
+        overlapped.hEvent = event.handle();
+
+        let mut len: DWORD = 0;
+        let ok = unsafe {
+            ReadFile(
+                self.handle,
+                buf.as_mut_ptr() as LPVOID,
+                buf.len() as DWORD,
+                &mut len,
+                &mut overlapped,
+            )
+        };
+
+        if ok != 0 {
+            return Ok(len as usize);
+        }
+
+        match unsafe { GetLastError() } {
+            ERROR_IO_PENDING => {
+                self.wait_overlapped(&mut overlapped, event.handle(), self.read_timeout)
+            }
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+
+    fn write_overlapped(&self, buf: &[u8]) -> io::Result<usize> {
+        let event = self
+            .write_event
+            .as_ref()
+            .expect("overlapped mode enabled without a write event");
+        let mut overlapped: OVERLAPPED = unsafe { mem::zeroed() };
+        overlapped.hEvent = event.handle();
+
+        let mut len: DWORD = 0;
+        let ok = unsafe {
+            WriteFile(
+                self.handle,
+                buf.as_ptr() as LPVOID,
+                buf.len() as DWORD,
+                &mut len,
+                &mut overlapped,
+            )
+        };
+
+        if ok != 0 {
+            return Ok(len as usize);
+        }
+
+        match unsafe { GetLastError() } {
+            ERROR_IO_PENDING => {
+                self.wait_overlapped(&mut overlapped, event.handle(), self.write_timeout)
+            }
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
 }
 
 impl Drop for SerialPort {
@@ -445,6 +900,10 @@ impl FromRawHandle for crate::SerialPort {
 
 impl io::Read for &SerialPort {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.overlapped {
+            return self.read_overlapped(buf);
+        }
+
         let mut len: DWORD = 0;
 
         match unsafe {
@@ -473,6 +932,10 @@ impl io::Read for &SerialPort {
 
 impl io::Write for &SerialPort {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.overlapped {
+            return self.write_overlapped(buf);
+        }
+
         let mut len: DWORD = 0;
 
         match unsafe {
@@ -0,0 +1,279 @@
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+
+use winapi::um::commapi::*;
+use winapi::um::winbase::*;
+use winapi::um::winnt::HANDLE;
+
+use super::com::{DtrControl, RtsControl};
+use crate::{DataBits, Error, ErrorKind, FlowControl, Parity, Result, StopBits};
+
+pub fn get_dcb(handle: HANDLE) -> Result<DCB> {
+    let mut dcb: DCB = unsafe { std::mem::zeroed() };
+    dcb.DCBlength = std::mem::size_of::<DCB>() as u32;
+
+    if unsafe { GetCommState(handle, &mut dcb) != 0 } {
+        Ok(dcb)
+    } else {
+        Err(super::error::last_os_error())
+    }
+}
+
+pub fn set_dcb(handle: HANDLE, mut dcb: DCB) -> Result<()> {
+    if unsafe { SetCommState(handle, &mut dcb) != 0 } {
+        Ok(())
+    } else {
+        Err(super::error::last_os_error())
+    }
+}
+
+/// Initializes the DCB with the flags this crate relies on being set to a known
+/// value, independent of whatever the driver's default happens to be.
+pub fn init(dcb: &mut DCB) {
+    dcb.set_fBinary(1);
+    dcb.set_fParity(0);
+    dcb.set_fOutxCtsFlow(0);
+    dcb.set_fOutxDsrFlow(0);
+    dcb.set_fDtrControl(DTR_CONTROL_DISABLE);
+    dcb.set_fDsrSensitivity(0);
+    dcb.set_fTXContinueOnXoff(0);
+    dcb.set_fOutX(0);
+    dcb.set_fInX(0);
+    dcb.set_fErrorChar(0);
+    dcb.set_fNull(0);
+    dcb.set_fRtsControl(RTS_CONTROL_DISABLE);
+    dcb.set_fAbortOnError(0);
+}
+
+pub fn set_baud_rate(dcb: &mut DCB, baud_rate: u32) {
+    dcb.BaudRate = baud_rate;
+}
+
+pub fn baud_rate(dcb: &DCB) -> u32 {
+    dcb.BaudRate as u32
+}
+
+pub fn set_data_bits(dcb: &mut DCB, data_bits: DataBits) {
+    dcb.ByteSize = match data_bits {
+        DataBits::Five => 5,
+        DataBits::Six => 6,
+        DataBits::Seven => 7,
+        DataBits::Eight => 8,
+    };
+}
+
+pub fn data_bits(dcb: &DCB) -> Result<DataBits> {
+    match dcb.ByteSize {
+        5 => Ok(DataBits::Five),
+        6 => Ok(DataBits::Six),
+        7 => Ok(DataBits::Seven),
+        8 => Ok(DataBits::Eight),
+        _ => Err(Error::new(
+            ErrorKind::Unknown,
+            "Invalid data bits setting encountered",
+        )),
+    }
+}
+
+pub fn set_parity(dcb: &mut DCB, parity: Parity) {
+    dcb.Parity = match parity {
+        Parity::None => NOPARITY,
+        Parity::Odd => ODDPARITY,
+        Parity::Even => EVENPARITY,
+        Parity::Mark => MARKPARITY,
+        Parity::Space => SPACEPARITY,
+    } as u8;
+
+    dcb.set_fParity(if parity == Parity::None { 0 } else { 1 });
+}
+
+pub fn parity(dcb: &DCB) -> Result<Parity> {
+    match dcb.Parity {
+        ODDPARITY => Ok(Parity::Odd),
+        EVENPARITY => Ok(Parity::Even),
+        NOPARITY => Ok(Parity::None),
+        MARKPARITY => Ok(Parity::Mark),
+        SPACEPARITY => Ok(Parity::Space),
+        _ => Err(Error::new(
+            ErrorKind::Unknown,
+            "Invalid parity bits setting encountered",
+        )),
+    }
+}
+
+pub fn set_stop_bits(dcb: &mut DCB, stop_bits: StopBits) {
+    dcb.StopBits = match stop_bits {
+        StopBits::One => ONESTOPBIT,
+        StopBits::Two => TWOSTOPBITS,
+    } as u8;
+}
+
+pub fn stop_bits(dcb: &DCB) -> Result<StopBits> {
+    match dcb.StopBits {
+        TWOSTOPBITS => Ok(StopBits::Two),
+        ONESTOPBIT => Ok(StopBits::One),
+        _ => Err(Error::new(
+            ErrorKind::Unknown,
+            "Invalid stop bits setting encountered",
+        )),
+    }
+}
+
+/// Applies `flow_control` to the DCB.
+///
+/// `fRtsControl` is only touched for `Hardware`, which owns the RTS line for its
+/// handshake and must force it to `RTS_CONTROL_HANDSHAKE`. For `None`/`Software`,
+/// `fRtsControl` is left as whatever `set_rts_control` already put there, so the
+/// builder's `rts_control` field isn't silently overridden by an unrelated setting.
+pub fn set_flow_control(dcb: &mut DCB, flow_control: FlowControl) {
+    match flow_control {
+        FlowControl::None => {
+            dcb.set_fOutxCtsFlow(0);
+            dcb.set_fOutX(0);
+            dcb.set_fInX(0);
+        }
+        FlowControl::Software => {
+            dcb.set_fOutxCtsFlow(0);
+            dcb.set_fOutX(1);
+            dcb.set_fInX(1);
+        }
+        FlowControl::Hardware => {
+            dcb.set_fOutxCtsFlow(1);
+            dcb.set_fRtsControl(RTS_CONTROL_HANDSHAKE);
+            dcb.set_fOutX(0);
+            dcb.set_fInX(0);
+        }
+    }
+}
+
+pub fn flow_control(dcb: &DCB) -> Result<FlowControl> {
+    if dcb.fOutxCtsFlow() != 0 || dcb.fRtsControl() == RTS_CONTROL_HANDSHAKE {
+        Ok(FlowControl::Hardware)
+    } else if dcb.fOutX() != 0 || dcb.fInX() != 0 {
+        Ok(FlowControl::Software)
+    } else {
+        Ok(FlowControl::None)
+    }
+}
+
+pub fn set_dtr_control(dcb: &mut DCB, dtr_control: DtrControl) {
+    let value = match dtr_control {
+        DtrControl::Disable => DTR_CONTROL_DISABLE,
+        DtrControl::Enable => DTR_CONTROL_ENABLE,
+        DtrControl::Handshake => DTR_CONTROL_HANDSHAKE,
+    };
+    dcb.set_fDtrControl(value);
+}
+
+pub fn dtr_control(dcb: &DCB) -> Result<DtrControl> {
+    match dcb.fDtrControl() {
+        DTR_CONTROL_DISABLE => Ok(DtrControl::Disable),
+        DTR_CONTROL_ENABLE => Ok(DtrControl::Enable),
+        DTR_CONTROL_HANDSHAKE => Ok(DtrControl::Handshake),
+        _ => Err(Error::new(
+            ErrorKind::Unknown,
+            "Invalid DTR control mode encountered",
+        )),
+    }
+}
+
+pub fn set_rts_control(dcb: &mut DCB, rts_control: RtsControl) {
+    let value = match rts_control {
+        RtsControl::Disable => RTS_CONTROL_DISABLE,
+        RtsControl::Enable => RTS_CONTROL_ENABLE,
+        RtsControl::Handshake => RTS_CONTROL_HANDSHAKE,
+        RtsControl::Toggle => RTS_CONTROL_TOGGLE,
+    };
+    dcb.set_fRtsControl(value);
+}
+
+pub fn rts_control(dcb: &DCB) -> Result<RtsControl> {
+    match dcb.fRtsControl() {
+        RTS_CONTROL_DISABLE => Ok(RtsControl::Disable),
+        RTS_CONTROL_ENABLE => Ok(RtsControl::Enable),
+        RTS_CONTROL_HANDSHAKE => Ok(RtsControl::Handshake),
+        RTS_CONTROL_TOGGLE => Ok(RtsControl::Toggle),
+        _ => Err(Error::new(
+            ErrorKind::Unknown,
+            "Invalid RTS control mode encountered",
+        )),
+    }
+}
+
+/// Parses `settings` (e.g. `"baud=1000000 data=8 parity=n stop=1"`) via
+/// `BuildCommDCBW`, the same Windows API `SerialPort::apply_settings_string` uses,
+/// starting from this crate's usual defaults (9600 8N1, no flow control, DTR/RTS
+/// disabled) rather than a live handle's current state.
+///
+/// Accepts whatever keyword set `BuildCommDCBW` itself accepts, so it never
+/// diverges from `apply_settings_string`'s grammar.
+pub fn parse_settings_string(settings: &str) -> Result<DCB> {
+    let mut dcb: DCB = unsafe { std::mem::zeroed() };
+    dcb.DCBlength = std::mem::size_of::<DCB>() as u32;
+    init(&mut dcb);
+    set_baud_rate(&mut dcb, 9600);
+    set_data_bits(&mut dcb, DataBits::Eight);
+    set_parity(&mut dcb, Parity::None);
+    set_stop_bits(&mut dcb, StopBits::One);
+    set_flow_control(&mut dcb, FlowControl::None);
+
+    let settings: Vec<u16> = OsStr::new(settings)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+
+    if unsafe { BuildCommDCBW(settings.as_ptr(), &mut dcb) } == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Could not parse settings string",
+        ));
+    }
+
+    Ok(dcb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zeroed_dcb() -> DCB {
+        unsafe { std::mem::zeroed() }
+    }
+
+    // set_flow_control(None/Software) must not clobber a previously applied
+    // rts_control; only Hardware is allowed to own fRtsControl.
+    #[test]
+    fn rts_control_round_trips_under_flow_control_none() {
+        for mode in [RtsControl::Enable, RtsControl::Toggle] {
+            let mut dcb = zeroed_dcb();
+            set_rts_control(&mut dcb, mode);
+            set_flow_control(&mut dcb, FlowControl::None);
+            assert_eq!(rts_control(&dcb).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn flow_control_hardware_forces_rts_handshake() {
+        let mut dcb = zeroed_dcb();
+        set_rts_control(&mut dcb, RtsControl::Disable);
+        set_flow_control(&mut dcb, FlowControl::Hardware);
+        assert_eq!(rts_control(&dcb).unwrap(), RtsControl::Handshake);
+    }
+
+    // parse_settings_string is a thin wrapper over BuildCommDCBW, so it must accept
+    // every keyword BuildCommDCB does, not just the baud/data/parity/stop subset a
+    // hand-rolled parser might recognize.
+    #[test]
+    fn parse_settings_string_accepts_full_build_comm_dcb_grammar() {
+        let dcb = parse_settings_string("baud=19200 parity=o data=7 stop=2 to=on xon=on").unwrap();
+        assert_eq!(baud_rate(&dcb), 19200);
+        assert_eq!(parity(&dcb).unwrap(), Parity::Odd);
+        assert_eq!(data_bits(&dcb).unwrap(), DataBits::Seven);
+        assert_eq!(stop_bits(&dcb).unwrap(), StopBits::Two);
+    }
+
+    #[test]
+    fn parse_settings_string_rejects_garbage() {
+        assert!(parse_settings_string("not a settings string").is_err());
+    }
+}